@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+
+use crate::game::events::{BallBounced, BrickDestroyed, CollectablePickedUp, LastBallDestroyed};
+use crate::game::InGameState;
+use crate::AppState;
+
+/// Typed handles for every clip the game plays, loaded on entering a game so
+/// playback systems never touch the asset server.
+#[derive(Resource)]
+pub struct GameAudio {
+    bounce: Handle<AudioSource>,
+    brick_break: Handle<AudioSource>,
+    collectable_pickup: Handle<AudioSource>,
+    last_ball_lost: Handle<AudioSource>,
+}
+
+pub struct AudioPlugin;
+
+impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::InGame), load_game_audio).add_systems(
+            Update,
+            (
+                play_bounce_sounds,
+                play_brick_break_sounds,
+                play_last_ball_lost_sounds,
+                play_collectable_pickup_sounds,
+            )
+                .run_if(in_state(InGameState::Play)),
+        );
+    }
+}
+
+fn load_game_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(GameAudio {
+        bounce: asset_server.load("audio/bounce.ogg"),
+        brick_break: asset_server.load("audio/brick_break.ogg"),
+        collectable_pickup: asset_server.load("audio/collectable_pickup.ogg"),
+        last_ball_lost: asset_server.load("audio/last_ball_lost.ogg"),
+    });
+}
+
+fn play_bounce_sounds(
+    mut commands: Commands,
+    game_audio: Res<GameAudio>,
+    mut bounced_events: EventReader<BallBounced>,
+) {
+    for _ in bounced_events.read() {
+        commands.spawn(AudioBundle {
+            source: game_audio.bounce.clone(),
+            settings: PlaybackSettings::DESPAWN,
+        });
+    }
+}
+
+fn play_brick_break_sounds(
+    mut commands: Commands,
+    game_audio: Res<GameAudio>,
+    mut brick_destroyed_events: EventReader<BrickDestroyed>,
+) {
+    for _ in brick_destroyed_events.read() {
+        commands.spawn(AudioBundle {
+            source: game_audio.brick_break.clone(),
+            settings: PlaybackSettings::DESPAWN,
+        });
+    }
+}
+
+fn play_last_ball_lost_sounds(
+    mut commands: Commands,
+    game_audio: Res<GameAudio>,
+    mut last_ball_destroyed_events: EventReader<LastBallDestroyed>,
+) {
+    for _ in last_ball_destroyed_events.read() {
+        commands.spawn(AudioBundle {
+            source: game_audio.last_ball_lost.clone(),
+            settings: PlaybackSettings::DESPAWN,
+        });
+    }
+}
+
+fn play_collectable_pickup_sounds(
+    mut commands: Commands,
+    game_audio: Res<GameAudio>,
+    mut picked_up_events: EventReader<CollectablePickedUp>,
+) {
+    for _ in picked_up_events.read() {
+        commands.spawn(AudioBundle {
+            source: game_audio.collectable_pickup.clone(),
+            settings: PlaybackSettings::DESPAWN,
+        });
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "debug")]
+
+//! A live-debugging overlay bound to F3. Only compiled when the `debug`
+//! cargo feature is enabled, so it never ships in release builds.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPlugin};
+
+use crate::game::brick::Brick;
+use crate::game::difficulty::GameTimer;
+use crate::game::entropy::GameSeed;
+use crate::game::resources::{BallSize, BallSpeed, BrickGhost, PaddleSize, PaddleSpeed};
+use crate::game::InGameState;
+
+#[derive(Resource, Default)]
+struct DebugPanelOpen(bool);
+
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+
+        app.init_resource::<DebugPanelOpen>()
+            .add_systems(Update, (toggle_debug_panel, draw_debug_panel).chain());
+    }
+}
+
+fn toggle_debug_panel(input: Res<ButtonInput<KeyCode>>, mut open: ResMut<DebugPanelOpen>) {
+    if input.just_pressed(KeyCode::F3) {
+        open.0 = !open.0;
+    }
+}
+
+fn draw_debug_panel(
+    open: Res<DebugPanelOpen>,
+    mut contexts: EguiContexts,
+    in_game_state: Res<State<InGameState>>,
+    game_timer: Res<GameTimer>,
+    seed: Res<GameSeed>,
+    balls: Query<(), With<crate::game::ball::components::Ball>>,
+    bricks: Query<(), With<Brick>>,
+    mut paddle_size: ResMut<PaddleSize>,
+    mut paddle_speed: ResMut<PaddleSpeed>,
+    mut ball_size: ResMut<BallSize>,
+    mut ball_speed: ResMut<BallSpeed>,
+    mut brick_ghost: ResMut<BrickGhost>,
+) {
+    if !open.0 {
+        return;
+    }
+
+    egui::Window::new("Debug Inspector").show(contexts.ctx_mut(), |ui| {
+        ui.label(format!("State: {:?}", in_game_state.get()));
+        ui.label(format!("Balls: {}", balls.iter().count()));
+        ui.label(format!("Bricks: {}", bricks.iter().count()));
+        ui.label(format!("Elapsed: {:.1}s", game_timer.elapsed_secs()));
+        ui.label(format!("Seed: {}", seed.value()));
+
+        ui.separator();
+
+        let mut paddle_size_points = paddle_size.points();
+        if ui
+            .add(egui::Slider::new(&mut paddle_size_points, 1..=5).text("Paddle Size"))
+            .changed()
+        {
+            let delta = paddle_size_points - paddle_size.points();
+            paddle_size.change_points(delta);
+        }
+
+        let mut paddle_speed_points = paddle_speed.points();
+        if ui
+            .add(egui::Slider::new(&mut paddle_speed_points, 1..=5).text("Paddle Speed"))
+            .changed()
+        {
+            let delta = paddle_speed_points - paddle_speed.points();
+            paddle_speed.change_points(delta);
+        }
+
+        let mut ball_size_points = ball_size.points();
+        if ui
+            .add(egui::Slider::new(&mut ball_size_points, 1..=5).text("Ball Size"))
+            .changed()
+        {
+            let delta = ball_size_points - ball_size.points();
+            ball_size.change_points(delta);
+        }
+
+        let mut ball_speed_points = ball_speed.points();
+        if ui
+            .add(egui::Slider::new(&mut ball_speed_points, 1..=5).text("Ball Speed"))
+            .changed()
+        {
+            let delta = ball_speed_points - ball_speed.points();
+            ball_speed.change_points(delta);
+        }
+
+        let mut ghost_enabled = brick_ghost.is_enabled();
+        if ui.checkbox(&mut ghost_enabled, "Brick Ghost").changed() {
+            brick_ghost.set_enabled(ghost_enabled);
+        }
+    });
+}
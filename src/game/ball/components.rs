@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use crate::game::resources::BallSpeed;
+
+/// A ball in play, carrying its own velocity independent of its transform.
+#[derive(Component)]
+pub struct Ball {
+    pub velocity: Vec2,
+}
+
+impl Ball {
+    pub fn new(direction: Vec2, speed: &BallSpeed) -> Self {
+        Self {
+            velocity: direction.normalize_or_zero() * speed.value(),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use bevy_rand::prelude::{GlobalEntropy, WyRand};
+use rand_core::RngCore;
+
+use crate::game::ball::components::Ball;
+use crate::game::ball::BALL_RADIUS;
+use crate::game::collider::{aabb_overlaps, Collider};
+use crate::game::events::BallBounced;
+use crate::game::resources::{BallSize, BallSpeed};
+use crate::game::shared::Collectable;
+
+const ARENA_HALF_WIDTH: f32 = 400.0;
+const ARENA_HALF_HEIGHT: f32 = 300.0;
+
+/// Launches the ball at a random angle drawn from the shared entropy
+/// source, so a given seed always produces the same opening shot.
+pub fn spawn_first_ball(
+    mut commands: Commands,
+    ball_speed: Res<BallSpeed>,
+    mut rng: ResMut<GlobalEntropy<WyRand>>,
+) {
+    let angle_fraction = (rng.next_u32() % 1000) as f32 / 1000.0;
+    let direction = Vec2::new(angle_fraction - 0.5, 1.0);
+
+    commands.spawn((
+        Ball::new(direction, &ball_speed),
+        Transform::default(),
+        GlobalTransform::default(),
+    ));
+}
+
+pub fn despawn_balls(mut commands: Commands, balls: Query<Entity, With<Ball>>) {
+    for entity in &balls {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn move_balls(time: Res<Time>, mut balls: Query<(&Ball, &mut Transform)>) {
+    for (ball, mut transform) in &mut balls {
+        transform.translation += ball.velocity.extend(0.0) * time.delta_seconds();
+    }
+}
+
+/// Bounces a ball back off bricks and the paddle, reported through
+/// `BallBounced` so reactive systems (audio) can key off the impact.
+pub fn bounce_ball_on_obstacles(
+    mut balls: Query<(&mut Ball, &Transform)>,
+    obstacles: Query<(&Transform, &Collider), (Without<Ball>, Without<Collectable>)>,
+    mut bounced_events: EventWriter<BallBounced>,
+) {
+    let ball_collider = Collider::new(Vec2::splat(BALL_RADIUS));
+
+    for (mut ball, transform) in &mut balls {
+        let position = transform.translation.truncate();
+        let hit = obstacles.iter().any(|(obstacle_transform, obstacle_collider)| {
+            aabb_overlaps(
+                position,
+                &ball_collider,
+                obstacle_transform.translation.truncate(),
+                obstacle_collider,
+            )
+        });
+
+        if hit {
+            ball.velocity.y = -ball.velocity.y;
+            bounced_events.send(BallBounced { position });
+        }
+    }
+}
+
+/// Bounces a ball off the side and top walls of the arena.
+pub fn bounce_ball_on_edges(
+    mut balls: Query<(&mut Ball, &Transform)>,
+    mut bounced_events: EventWriter<BallBounced>,
+) {
+    for (mut ball, transform) in &mut balls {
+        let position = transform.translation.truncate();
+        let mut bounced = false;
+
+        if position.x - BALL_RADIUS <= -ARENA_HALF_WIDTH
+            || position.x + BALL_RADIUS >= ARENA_HALF_WIDTH
+        {
+            ball.velocity.x = -ball.velocity.x;
+            bounced = true;
+        }
+        if position.y + BALL_RADIUS >= ARENA_HALF_HEIGHT {
+            ball.velocity.y = -ball.velocity.y;
+            bounced = true;
+        }
+
+        if bounced {
+            bounced_events.send(BallBounced { position });
+        }
+    }
+}
+
+pub fn keep_ball_synced_with_settings(
+    ball_size: Res<BallSize>,
+    ball_speed: Res<BallSpeed>,
+    mut balls: Query<(&mut Ball, &mut Transform)>,
+) {
+    if !ball_size.is_changed() && !ball_speed.is_changed() {
+        return;
+    }
+
+    for (mut ball, mut transform) in &mut balls {
+        transform.scale = Vec3::splat(0.8 + 0.1 * ball_size.points() as f32);
+        ball.velocity = ball.velocity.normalize_or_zero() * ball_speed.value();
+    }
+}
+
+pub fn keep_destroying_balls(
+    mut commands: Commands,
+    balls: Query<(Entity, &Transform), With<Ball>>,
+    mut last_ball_destroyed_events: EventWriter<crate::game::events::LastBallDestroyed>,
+) {
+    let remaining_before = balls.iter().count();
+    let mut destroyed = 0;
+
+    for (entity, transform) in &balls {
+        if transform.translation.y - BALL_RADIUS < -ARENA_HALF_HEIGHT {
+            commands.entity(entity).despawn();
+            destroyed += 1;
+        }
+    }
+
+    if destroyed > 0 && destroyed >= remaining_before {
+        last_ball_destroyed_events.send(crate::game::events::LastBallDestroyed);
+    }
+}
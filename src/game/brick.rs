@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use bevy_rand::prelude::{GlobalEntropy, WyRand};
+use rand_core::RngCore;
+
+use crate::game::ball::components::Ball;
+use crate::game::ball::BALL_RADIUS;
+use crate::game::collider::{aabb_overlaps, Collider};
+use crate::game::events::BrickDestroyed;
+use crate::game::resources::{BrickGhost, BrickRowSpawnCooldown, Score};
+
+const ARENA_HALF_WIDTH: f32 = 400.0;
+const BRICK_HALF_EXTENTS: Vec2 = Vec2::new(36.0, 14.0);
+const BRICKS_PER_ROW: u32 = 8;
+const ROW_SPAWN_Y: f32 = 260.0;
+const GAP_CHANCE_PERCENT: u32 = 15;
+const POINTS_PER_BRICK: u32 = 10;
+
+#[derive(Component)]
+pub struct Brick;
+
+pub fn spawn_bricks(mut commands: Commands, mut rng: ResMut<GlobalEntropy<WyRand>>) {
+    spawn_row(&mut commands, &mut rng);
+}
+
+pub fn despawn_bricks(mut commands: Commands, bricks: Query<Entity, With<Brick>>) {
+    for entity in &bricks {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn destroy_bricks_on_hit(
+    mut commands: Commands,
+    balls: Query<&Transform, With<Ball>>,
+    bricks: Query<(Entity, &Transform, &Collider), With<Brick>>,
+    mut score: ResMut<Score>,
+    mut brick_destroyed_events: EventWriter<BrickDestroyed>,
+) {
+    let ball_collider = Collider::new(Vec2::splat(BALL_RADIUS));
+
+    for ball_transform in &balls {
+        let ball_position = ball_transform.translation.truncate();
+
+        for (entity, brick_transform, brick_collider) in &bricks {
+            let brick_position = brick_transform.translation.truncate();
+            if aabb_overlaps(ball_position, &ball_collider, brick_position, brick_collider) {
+                commands.entity(entity).despawn();
+                score.add(POINTS_PER_BRICK);
+                brick_destroyed_events.send(BrickDestroyed {
+                    position: brick_position,
+                });
+            }
+        }
+    }
+}
+
+/// Spawns a fresh row of bricks once `BrickRowSpawnCooldown` elapses. The
+/// row layout is drawn from the shared entropy source, so a given seed
+/// always reproduces the same brick sequence.
+pub fn keep_spawning_bricks(
+    time: Res<Time>,
+    mut cooldown: ResMut<BrickRowSpawnCooldown>,
+    mut rng: ResMut<GlobalEntropy<WyRand>>,
+    mut commands: Commands,
+) {
+    if !cooldown.timer_mut().tick(time.delta()).just_finished() {
+        return;
+    }
+
+    spawn_row(&mut commands, &mut rng);
+}
+
+fn spawn_row(commands: &mut Commands, rng: &mut GlobalEntropy<WyRand>) {
+    let gap = (ARENA_HALF_WIDTH * 2.0) / BRICKS_PER_ROW as f32;
+
+    for index in 0..BRICKS_PER_ROW {
+        if rng.next_u32() % 100 < GAP_CHANCE_PERCENT {
+            continue;
+        }
+
+        let x = -ARENA_HALF_WIDTH + gap * (index as f32 + 0.5);
+        commands.spawn((
+            Brick,
+            Collider::new(BRICK_HALF_EXTENTS),
+            Transform::from_xyz(x, ROW_SPAWN_Y, 0.0),
+            GlobalTransform::default(),
+        ));
+    }
+}
+
+pub fn keep_brick_synced_with_settings(
+    brick_ghost: Res<BrickGhost>,
+    mut bricks: Query<&mut Visibility, With<Brick>>,
+) {
+    if !brick_ghost.is_changed() {
+        return;
+    }
+
+    let visibility = if brick_ghost.is_enabled() {
+        Visibility::Hidden
+    } else {
+        Visibility::Visible
+    };
+
+    for mut brick_visibility in &mut bricks {
+        *brick_visibility = visibility;
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use bevy_rand::prelude::{GlobalEntropy, WyRand};
+use rand_core::RngCore;
+
+use crate::game::collider::Collider;
+use crate::game::events::BrickDestroyed;
+use crate::game::shared::Collectable;
+
+const COLLECTABLE_HALF_EXTENTS: Vec2 = Vec2::splat(10.0);
+const DROP_CHANCE_PERCENT: u32 = 20;
+const POINTS_PER_COLLECTABLE: u32 = 25;
+
+pub fn despawn_collectables(mut commands: Commands, collectables: Query<Entity, With<Collectable>>) {
+    for entity in &collectables {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Occasionally drops a collectable where a brick was just destroyed. The
+/// drop roll is drawn from the shared entropy source, so a given seed always
+/// reproduces the same collectable sequence.
+pub fn keep_spawning_collectables(
+    mut commands: Commands,
+    mut rng: ResMut<GlobalEntropy<WyRand>>,
+    mut brick_destroyed_events: EventReader<BrickDestroyed>,
+) {
+    for event in brick_destroyed_events.read() {
+        if rng.next_u32() % 100 >= DROP_CHANCE_PERCENT {
+            continue;
+        }
+
+        commands.spawn((
+            Collectable {
+                points: POINTS_PER_COLLECTABLE,
+            },
+            Collider::new(COLLECTABLE_HALF_EXTENTS),
+            Transform::from_translation(event.position.extend(0.0)),
+            GlobalTransform::default(),
+        ));
+    }
+}
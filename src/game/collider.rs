@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+/// An axis-aligned bounding box, attached to anything a ball or the paddle
+/// can collide with: bricks, the paddle itself, collectables.
+#[derive(Component, Clone, Copy)]
+pub struct Collider {
+    pub half_extents: Vec2,
+}
+
+impl Collider {
+    pub fn new(half_extents: Vec2) -> Self {
+        Self { half_extents }
+    }
+}
+
+/// Axis-aligned overlap test between two `Collider`s at the given positions.
+pub fn aabb_overlaps(a_position: Vec2, a: &Collider, b_position: Vec2, b: &Collider) -> bool {
+    let delta = (a_position - b_position).abs();
+    delta.x <= a.half_extents.x + b.half_extents.x && delta.y <= a.half_extents.y + b.half_extents.y
+}
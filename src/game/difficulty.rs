@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy::time::Stopwatch;
+
+use crate::game::resources::{BallSpeed, BrickRowSpawnCooldown};
+
+/// Seconds of survival time between each difficulty bump.
+const DIFFICULTY_INTERVAL_SECS: f32 = 20.0;
+
+/// Tracks how long the current run has spent in `InGameState::Play`.
+///
+/// Ticked only while playing; paused, preparing, or summarising a run leaves
+/// the elapsed time frozen so the difficulty ramp can't advance off-screen.
+#[derive(Resource, Default)]
+pub struct GameTimer(Stopwatch);
+
+impl GameTimer {
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    pub fn elapsed_secs(&self) -> f32 {
+        self.0.elapsed_secs()
+    }
+
+    fn difficulty_level(&self) -> u32 {
+        (self.elapsed_secs() / DIFFICULTY_INTERVAL_SECS) as u32
+    }
+}
+
+pub fn tick_game_timer(time: Res<Time>, mut game_timer: ResMut<GameTimer>) {
+    game_timer.0.tick(time.delta());
+}
+
+/// Recomputes the spawn cadence and ball speed from elapsed play-time every
+/// frame, rather than keeping them constant for the whole run.
+pub fn update_difficulty(
+    game_timer: Res<GameTimer>,
+    mut cooldown: ResMut<BrickRowSpawnCooldown>,
+    mut ball_speed: ResMut<BallSpeed>,
+) {
+    let level = game_timer.difficulty_level();
+    cooldown.set_difficulty_level(level);
+    ball_speed.set_difficulty_level(level);
+}
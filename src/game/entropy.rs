@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy_rand::prelude::{EntropyPlugin as BevyEntropyPlugin, GlobalEntropy, WyRand};
+use rand_core::SeedableRng;
+
+/// The seed driving every randomized gameplay decision this run. Every
+/// system that needs randomness draws from the single `GlobalEntropy<WyRand>`
+/// resource seeded from this value, so a shared seed reproduces an identical
+/// brick/collectable sequence.
+#[derive(Resource, Clone, Copy)]
+pub struct GameSeed(u64);
+
+impl Default for GameSeed {
+    fn default() -> Self {
+        Self::daily()
+    }
+}
+
+impl GameSeed {
+    pub fn from_value(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// A seed derived from today's date, used when the player doesn't enter
+    /// one of their own, so two players who both pick "daily" get the same run.
+    pub fn daily() -> Self {
+        // `SystemTime::now` has no wasm32 implementation in `std`; fall back
+        // to a fixed seed there rather than panicking.
+        #[cfg(not(target_arch = "wasm32"))]
+        let days_since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_secs() / 86_400)
+            .unwrap_or_default();
+        #[cfg(target_arch = "wasm32")]
+        let days_since_epoch = 0;
+
+        Self(days_since_epoch)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+pub struct EntropyPlugin;
+
+impl Plugin for EntropyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameSeed>()
+            .add_plugins(BevyEntropyPlugin::<WyRand>::default())
+            .add_systems(
+                OnEnter(crate::AppState::InGame),
+                reseed_entropy.in_set(crate::game::GameStartupSet::Entropy),
+            );
+    }
+}
+
+/// Reseeds the shared RNG from the active `GameSeed` each time a run starts,
+/// so restarting with the same seed reproduces the same run.
+fn reseed_entropy(seed: Res<GameSeed>, mut rng: ResMut<GlobalEntropy<WyRand>>) {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&seed.value().to_le_bytes());
+    *rng = GlobalEntropy::from_seed(bytes);
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// Fired when a brick is destroyed, carrying its last position so effects
+/// (sparks, sound) can be spawned at the right spot.
+#[derive(Event)]
+pub struct BrickDestroyed {
+    pub position: Vec2,
+}
+
+/// Fired when the last ball in play falls off the bottom of the screen.
+#[derive(Event)]
+pub struct LastBallDestroyed;
+
+/// Fired whenever a ball bounces off a wall, the paddle, or a brick.
+#[derive(Event)]
+pub struct BallBounced {
+    pub position: Vec2,
+}
+
+/// Fired when the paddle catches a collectable.
+#[derive(Event)]
+pub struct CollectablePickedUp;
+
+#[derive(Event)]
+pub struct MenuRequested;
+
+#[derive(Event)]
+pub struct RestartRequested;
+
+#[derive(Event)]
+pub struct TogglePauseRequested;
@@ -1,7 +1,9 @@
 pub mod ball;
-mod brick;
+pub(crate) mod brick;
 mod collectable;
 pub mod collider;
+pub mod difficulty;
+pub mod entropy;
 pub mod events;
 mod paddle;
 mod pause_view;
@@ -15,11 +17,17 @@ mod summary_view;
 use crate::AppState;
 use bevy::prelude::*;
 
-use crate::game::ball::{keep_ball_synced_with_settings, keep_destroying_balls};
+use crate::game::ball::{
+    bounce_ball_on_edges, bounce_ball_on_obstacles, keep_ball_synced_with_settings,
+    keep_destroying_balls,
+};
 use crate::game::brick::{keep_brick_synced_with_settings, keep_spawning_bricks};
 use crate::game::collectable::{despawn_collectables, keep_spawning_collectables};
+use crate::game::difficulty::{tick_game_timer, update_difficulty, GameTimer};
+use crate::game::entropy::{EntropyPlugin, GameSeed};
 use crate::game::events::{
-    BrickDestroyed, LastBallDestroyed, MenuRequested, RestartRequested, TogglePauseRequested,
+    BallBounced, BrickDestroyed, CollectablePickedUp, LastBallDestroyed, MenuRequested,
+    RestartRequested, TogglePauseRequested,
 };
 use crate::game::pause_view::{check_pause_interactions, despawn_pause_view, spawn_pause_view};
 use crate::game::preparation_view::{despawn_preparation_view, spawn_preparation_view};
@@ -30,8 +38,10 @@ use crate::game::score_view::{despawn_score_view, spawn_score_view, update_score
 use crate::game::shared::{collect_collectables, keep_ball_at_paddle_center};
 use crate::game::spark::{keep_despawning_sparks, move_sparks};
 use crate::game::summary_view::{
-    check_summary_interactions, despawn_summary_view, spawn_summary_view,
+    check_summary_interactions, despawn_summary_view, spawn_summary_view, NewHighScore,
 };
+use crate::audio::AudioPlugin;
+use crate::profile::HighScores;
 use ball::{despawn_balls, move_balls, spawn_first_ball};
 use brick::{despawn_bricks, destroy_bricks_on_hit, spawn_bricks};
 use paddle::{despawn_paddles, keep_paddle_synced_with_settings, move_paddle, spawn_paddle};
@@ -39,7 +49,7 @@ use paddle::{despawn_paddles, keep_paddle_synced_with_settings, move_paddle, spa
 pub struct GamePlugin;
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
-enum InGameState {
+pub(crate) enum InGameState {
     #[default]
     None,
     Preparation,
@@ -48,10 +58,31 @@ enum InGameState {
     Summary,
 }
 
+/// Orders the one-shot `OnEnter(AppState::InGame)` startup systems so the
+/// shared entropy source is reseeded before anything draws from it to
+/// place bricks, collectables, or the opening ball.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, SystemSet)]
+pub(crate) enum GameStartupSet {
+    Entropy,
+    Spawn,
+}
+
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<InGameState>()
-            .init_resource::<Score>()
+            .configure_sets(
+                OnEnter(AppState::InGame),
+                GameStartupSet::Entropy.before(GameStartupSet::Spawn),
+            )
+            .add_plugins(EntropyPlugin)
+            .add_plugins(AudioPlugin);
+
+        #[cfg(feature = "debug")]
+        app.add_plugins(crate::debug::DebugPlugin);
+
+        app.init_resource::<Score>()
+            .init_resource::<NewHighScore>()
+            .init_resource::<GameTimer>()
             .init_resource::<BrickRowSpawnCooldown>()
             .init_resource::<BallSize>()
             .init_resource::<BallSpeed>()
@@ -60,6 +91,8 @@ impl Plugin for GamePlugin {
             .init_resource::<PaddleSpeed>()
             .add_event::<BrickDestroyed>()
             .add_event::<LastBallDestroyed>()
+            .add_event::<BallBounced>()
+            .add_event::<CollectablePickedUp>()
             .add_event::<RestartRequested>()
             .add_event::<MenuRequested>()
             .add_event::<TogglePauseRequested>()
@@ -68,9 +101,9 @@ impl Plugin for GamePlugin {
                 OnEnter(AppState::InGame),
                 (
                     spawn_score_view,
-                    spawn_first_ball,
+                    spawn_first_ball.in_set(GameStartupSet::Spawn),
                     spawn_paddle,
-                    spawn_bricks,
+                    spawn_bricks.in_set(GameStartupSet::Spawn),
                     start_up,
                 ),
             )
@@ -91,29 +124,40 @@ impl Plugin for GamePlugin {
             .add_systems(OnExit(InGameState::Pause), despawn_pause_view)
             .add_systems(OnEnter(InGameState::Summary), spawn_summary_view)
             .add_systems(OnExit(InGameState::Summary), despawn_summary_view)
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
-                    (
-                        (move_paddle, keep_ball_at_paddle_center).chain(),
-                        check_preparation_end_condition,
-                    )
+                    (move_paddle, keep_ball_at_paddle_center)
+                        .chain()
                         .run_if(in_state(InGameState::Preparation)),
                     (
-                        update_score_view,
                         move_paddle,
                         move_balls,
+                        bounce_ball_on_obstacles,
+                        bounce_ball_on_edges,
                         destroy_bricks_on_hit,
-                        test_settings,
+                        keep_spawning_bricks,
+                        keep_spawning_collectables,
+                    )
+                        .chain()
+                        .run_if(in_state(InGameState::Play)),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    (check_preparation_end_condition,).run_if(in_state(InGameState::Preparation)),
+                    (
+                        update_score_view,
                         keep_ball_synced_with_settings,
                         keep_destroying_balls,
                         keep_paddle_synced_with_settings,
                         keep_brick_synced_with_settings,
-                        keep_spawning_bricks,
-                        keep_spawning_collectables,
                         move_sparks,
                         keep_despawning_sparks,
                         collect_collectables,
+                        (tick_game_timer, update_difficulty).chain(),
                     )
                         .run_if(in_state(InGameState::Play)),
                     (check_pause_interactions,).run_if(in_state(InGameState::Pause)),
@@ -130,13 +174,15 @@ impl Plugin for GamePlugin {
     }
 }
 
-fn start_up(mut next_state: ResMut<NextState<InGameState>>) {
+fn start_up(mut next_state: ResMut<NextState<InGameState>>, mut game_timer: ResMut<GameTimer>) {
+    game_timer.reset();
     next_state.set(InGameState::Preparation);
 }
 
 fn clean_up(mut commands: Commands, mut next_state: ResMut<NextState<InGameState>>) {
     next_state.set(InGameState::None);
     commands.insert_resource(Score::default());
+    commands.insert_resource(GameTimer::default());
     commands.insert_resource(BrickRowSpawnCooldown::default());
     commands.insert_resource(BallSize::default());
     commands.insert_resource(BallSpeed::default());
@@ -188,14 +234,34 @@ fn continue_restart_game(mut next_state: ResMut<NextState<AppState>>) {
 }
 
 fn check_summary_condition(
+    mut commands: Commands,
     mut last_ball_destroyed_events: EventReader<LastBallDestroyed>,
     mut next_state: ResMut<NextState<InGameState>>,
+    score: Res<Score>,
+    mut high_scores: ResMut<HighScores>,
 ) {
     if last_ball_destroyed_events.is_empty() {
         return;
     }
 
     last_ball_destroyed_events.clear();
+
+    // `SystemTime::now` has no wasm32 implementation in `std`; fall back to
+    // an unspecified timestamp there rather than panicking.
+    #[cfg(not(target_arch = "wasm32"))]
+    let recorded_at = std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    #[cfg(target_arch = "wasm32")]
+    let recorded_at = 0;
+
+    let is_new_high_score = high_scores.insert_if_qualifies(score.value(), recorded_at);
+    if is_new_high_score {
+        high_scores.save();
+    }
+    commands.insert_resource(NewHighScore(is_new_high_score));
+
     next_state.set(InGameState::Summary);
 }
 
@@ -226,39 +292,3 @@ fn check_toggle_pause_condition(
     }
 }
 
-pub fn test_settings(
-    input: Res<ButtonInput<KeyCode>>,
-    mut ball_size: ResMut<BallSize>,
-    mut ball_speed: ResMut<BallSpeed>,
-    mut brick_ghost: ResMut<BrickGhost>,
-    mut paddle_size: ResMut<PaddleSize>,
-    mut paddle_speed: ResMut<PaddleSpeed>,
-) {
-    let value = if input.just_pressed(KeyCode::KeyQ) {
-        -1
-    } else if input.just_pressed(KeyCode::KeyE) {
-        1
-    } else {
-        0
-    };
-
-    if value == 0 {
-        return;
-    }
-
-    if input.pressed(KeyCode::Digit1) {
-        paddle_size.change_points(value);
-    }
-    if input.pressed(KeyCode::Digit2) {
-        paddle_speed.change_points(value);
-    }
-    if input.pressed(KeyCode::Digit3) {
-        ball_size.change_points(value);
-    }
-    if input.pressed(KeyCode::Digit4) {
-        ball_speed.change_points(value);
-    }
-    if input.pressed(KeyCode::Digit5) {
-        brick_ghost.set_enabled(value > 0);
-    }
-}
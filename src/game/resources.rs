@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::game::ball::BALL_SPEED;
+
+const MIN_POINTS: i32 = 1;
+const MAX_POINTS: i32 = 5;
+const DEFAULT_POINTS: i32 = 3;
+
+const BASE_ROW_COOLDOWN_SECS: f32 = 6.0;
+const MIN_ROW_COOLDOWN_SECS: f32 = 1.5;
+const MAX_DIFFICULTY_LEVEL: u32 = 8;
+
+/// Run score, shown in the HUD and carried into the summary view.
+#[derive(Resource, Default)]
+pub struct Score(u32);
+
+impl Score {
+    pub fn add(&mut self, points: u32) {
+        self.0 += points;
+    }
+
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+/// A five-point dial tuned from the debug inspector, clamped to `[1, 5]`.
+#[derive(Resource, Clone, Copy)]
+pub struct PaddleSize(i32);
+
+impl Default for PaddleSize {
+    fn default() -> Self {
+        Self(DEFAULT_POINTS)
+    }
+}
+
+impl PaddleSize {
+    pub fn change_points(&mut self, delta: i32) {
+        self.0 = (self.0 + delta).clamp(MIN_POINTS, MAX_POINTS);
+    }
+
+    pub fn points(&self) -> i32 {
+        self.0
+    }
+}
+
+/// A five-point dial tuned from the debug inspector, clamped to `[1, 5]`.
+#[derive(Resource, Clone, Copy)]
+pub struct PaddleSpeed(i32);
+
+impl Default for PaddleSpeed {
+    fn default() -> Self {
+        Self(DEFAULT_POINTS)
+    }
+}
+
+impl PaddleSpeed {
+    pub fn change_points(&mut self, delta: i32) {
+        self.0 = (self.0 + delta).clamp(MIN_POINTS, MAX_POINTS);
+    }
+
+    pub fn points(&self) -> i32 {
+        self.0
+    }
+}
+
+/// A five-point dial tuned from the debug inspector, clamped to `[1, 5]`.
+#[derive(Resource, Clone, Copy)]
+pub struct BallSize(i32);
+
+impl Default for BallSize {
+    fn default() -> Self {
+        Self(DEFAULT_POINTS)
+    }
+}
+
+impl BallSize {
+    pub fn change_points(&mut self, delta: i32) {
+        self.0 = (self.0 + delta).clamp(MIN_POINTS, MAX_POINTS);
+    }
+
+    pub fn points(&self) -> i32 {
+        self.0
+    }
+}
+
+/// Lets bricks stay see-through so balls can be watched sailing past them.
+#[derive(Resource, Default, Clone, Copy)]
+pub struct BrickGhost(bool);
+
+impl BrickGhost {
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.0 = enabled;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0
+    }
+}
+
+/// The ball's travel speed: a player-tunable dial nudged further by the
+/// difficulty ramp as a run survives longer.
+#[derive(Resource, Clone, Copy)]
+pub struct BallSpeed {
+    points: i32,
+    difficulty_level: u32,
+}
+
+impl Default for BallSpeed {
+    fn default() -> Self {
+        Self {
+            points: DEFAULT_POINTS,
+            difficulty_level: 0,
+        }
+    }
+}
+
+impl BallSpeed {
+    pub fn change_points(&mut self, delta: i32) {
+        self.points = (self.points + delta).clamp(MIN_POINTS, MAX_POINTS);
+    }
+
+    pub fn points(&self) -> i32 {
+        self.points
+    }
+
+    /// Called by the difficulty ramp; higher levels push the ball faster.
+    pub fn set_difficulty_level(&mut self, level: u32) {
+        self.difficulty_level = level.min(MAX_DIFFICULTY_LEVEL);
+    }
+
+    /// Resolves the dial and the difficulty bonus into an actual speed in px/s.
+    pub fn value(&self) -> f32 {
+        let dial = BALL_SPEED * (0.8 + 0.1 * self.points as f32);
+        let difficulty_bonus = 1.0 + 0.08 * self.difficulty_level as f32;
+        dial * difficulty_bonus
+    }
+}
+
+/// How often a new row of bricks spawns, shortened by the difficulty ramp.
+#[derive(Resource)]
+pub struct BrickRowSpawnCooldown(Timer);
+
+impl Default for BrickRowSpawnCooldown {
+    fn default() -> Self {
+        Self(Timer::from_seconds(
+            BASE_ROW_COOLDOWN_SECS,
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+impl BrickRowSpawnCooldown {
+    pub fn timer(&self) -> &Timer {
+        &self.0
+    }
+
+    pub fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.0
+    }
+
+    /// Called by the difficulty ramp; higher levels spawn rows more often.
+    pub fn set_difficulty_level(&mut self, level: u32) {
+        let level = level.min(MAX_DIFFICULTY_LEVEL);
+        let secs = (BASE_ROW_COOLDOWN_SECS - level as f32 * 0.5).max(MIN_ROW_COOLDOWN_SECS);
+        self.0.set_duration(Duration::from_secs_f32(secs));
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::game::ball::components::Ball;
+use crate::game::ball::BALL_RADIUS;
+use crate::game::collider::{aabb_overlaps, Collider};
+use crate::game::events::CollectablePickedUp;
+use crate::game::resources::Score;
+
+/// Marker for the single paddle entity the ball rests on during `Preparation`.
+#[derive(Component)]
+pub struct Paddle;
+
+/// Marker for a collectable the paddle can catch for bonus points.
+#[derive(Component)]
+pub struct Collectable {
+    pub points: u32,
+}
+
+pub fn keep_ball_at_paddle_center(
+    paddles: Query<&Transform, With<Paddle>>,
+    mut balls: Query<&mut Transform, (With<Ball>, Without<Paddle>)>,
+) {
+    let Ok(paddle_transform) = paddles.get_single() else {
+        return;
+    };
+
+    for mut ball_transform in &mut balls {
+        ball_transform.translation.x = paddle_transform.translation.x;
+        ball_transform.translation.y = paddle_transform.translation.y + BALL_RADIUS + 4.0;
+    }
+}
+
+/// Despawns any collectable the paddle catches, adds its points to the
+/// score, and reports the pickup so audio can react.
+pub fn collect_collectables(
+    mut commands: Commands,
+    paddles: Query<(&Transform, &Collider), With<Paddle>>,
+    collectables: Query<(Entity, &Transform, &Collider, &Collectable)>,
+    mut score: ResMut<Score>,
+    mut picked_up_events: EventWriter<CollectablePickedUp>,
+) {
+    let Ok((paddle_transform, paddle_collider)) = paddles.get_single() else {
+        return;
+    };
+
+    for (entity, transform, collider, collectable) in &collectables {
+        if aabb_overlaps(
+            paddle_transform.translation.truncate(),
+            paddle_collider,
+            transform.translation.truncate(),
+            collider,
+        ) {
+            commands.entity(entity).despawn();
+            score.add(collectable.points);
+            picked_up_events.send(CollectablePickedUp);
+        }
+    }
+}
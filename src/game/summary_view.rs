@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+
+use crate::game::entropy::GameSeed;
+use crate::game::events::{MenuRequested, RestartRequested};
+use crate::game::resources::Score;
+use crate::profile::HighScores;
+
+/// Whether the run that just ended cracked the high-score table; set by
+/// `check_summary_condition` before entering `InGameState::Summary`.
+#[derive(Resource, Default)]
+pub struct NewHighScore(pub bool);
+
+#[derive(Component)]
+pub struct SummaryView;
+
+#[derive(Component)]
+pub(crate) enum SummaryButton {
+    Restart,
+    Menu,
+}
+
+pub fn spawn_summary_view(
+    mut commands: Commands,
+    score: Res<Score>,
+    high_scores: Res<HighScores>,
+    new_high_score: Res<NewHighScore>,
+    seed: Res<GameSeed>,
+) {
+    commands
+        .spawn((
+            SummaryView,
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                format!("Score: {}", score.value()),
+                TextStyle::default(),
+            ));
+            parent.spawn(TextBundle::from_section(
+                format!("Seed: {}", seed.value()),
+                TextStyle::default(),
+            ));
+
+            if new_high_score.0 {
+                parent.spawn(TextBundle::from_section("New high score!", TextStyle::default()));
+            }
+
+            for (rank, entry) in high_scores.entries().iter().enumerate() {
+                parent.spawn(TextBundle::from_section(
+                    format!("{}. {}", rank + 1, entry.score),
+                    TextStyle::default(),
+                ));
+            }
+
+            parent
+                .spawn((ButtonBundle::default(), SummaryButton::Restart))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section("Restart", TextStyle::default()));
+                });
+            parent
+                .spawn((ButtonBundle::default(), SummaryButton::Menu))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section("Menu", TextStyle::default()));
+                });
+        });
+}
+
+pub fn despawn_summary_view(mut commands: Commands, views: Query<Entity, With<SummaryView>>) {
+    for entity in &views {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn check_summary_interactions(
+    buttons: Query<(&Interaction, &SummaryButton), Changed<Interaction>>,
+    mut restart_requested_events: EventWriter<RestartRequested>,
+    mut menu_requested_events: EventWriter<MenuRequested>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button {
+            SummaryButton::Restart => {
+                restart_requested_events.send(RestartRequested);
+            }
+            SummaryButton::Menu => {
+                menu_requested_events.send(MenuRequested);
+            }
+        }
+    }
+}
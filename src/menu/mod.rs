@@ -1,6 +1,8 @@
 mod menu_view;
 
+use crate::game::entropy::GameSeed;
 use crate::menu::menu_view::{check_menu_interactions, despawn_menu_ui, spawn_menu_ui};
+use crate::profile::load_high_scores;
 use crate::AppState;
 use bevy::prelude::*;
 
@@ -8,11 +10,91 @@ pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::Menu), spawn_menu_ui)
+        app.add_systems(Startup, load_high_scores)
+            .add_systems(
+                OnEnter(AppState::Menu),
+                (spawn_menu_ui, spawn_seed_display),
+            )
             .add_systems(
                 Update,
-                (check_menu_interactions,).run_if(in_state(AppState::Menu)),
+                (check_menu_interactions, check_seed_input, update_seed_display)
+                    .run_if(in_state(AppState::Menu)),
             )
-            .add_systems(OnExit(AppState::Menu), despawn_menu_ui);
+            .add_systems(
+                OnExit(AppState::Menu),
+                (despawn_menu_ui, despawn_seed_display),
+            );
+    }
+}
+
+/// Shows the seed the next run will use, so a player who typed one can copy
+/// it before starting, rather than only seeing it after a run ends.
+#[derive(Component)]
+struct SeedDisplay;
+
+fn spawn_seed_display(mut commands: Commands, seed: Res<GameSeed>) {
+    commands.spawn((
+        SeedDisplay,
+        TextBundle::from_section(seed_label(&seed), TextStyle::default()),
+    ));
+}
+
+fn despawn_seed_display(mut commands: Commands, displays: Query<Entity, With<SeedDisplay>>) {
+    for entity in &displays {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn update_seed_display(
+    seed: Res<GameSeed>,
+    mut displays: Query<&mut Text, With<SeedDisplay>>,
+) {
+    if !seed.is_changed() {
+        return;
+    }
+
+    for mut text in &mut displays {
+        *text = Text::from_section(seed_label(&seed), TextStyle::default());
+    }
+}
+
+fn seed_label(seed: &GameSeed) -> String {
+    format!("Seed: {}", seed.value())
+}
+
+/// Lets a player type digits to pick a shareable seed before starting a
+/// game; Backspace clears back to the date-derived daily seed.
+fn check_seed_input(
+    input: Res<ButtonInput<KeyCode>>,
+    mut entered_digits: Local<String>,
+    mut seed: ResMut<GameSeed>,
+) {
+    const DIGIT_KEYS: [(KeyCode, char); 10] = [
+        (KeyCode::Digit0, '0'),
+        (KeyCode::Digit1, '1'),
+        (KeyCode::Digit2, '2'),
+        (KeyCode::Digit3, '3'),
+        (KeyCode::Digit4, '4'),
+        (KeyCode::Digit5, '5'),
+        (KeyCode::Digit6, '6'),
+        (KeyCode::Digit7, '7'),
+        (KeyCode::Digit8, '8'),
+        (KeyCode::Digit9, '9'),
+    ];
+
+    if input.just_pressed(KeyCode::Backspace) {
+        entered_digits.clear();
+        *seed = GameSeed::daily();
+        return;
+    }
+
+    for (key, digit) in DIGIT_KEYS {
+        if input.just_pressed(key) {
+            entered_digits.push(digit);
+        }
+    }
+
+    if let Ok(value) = entered_digits.parse::<u64>() {
+        *seed = GameSeed::from_value(value);
     }
 }
@@ -0,0 +1,163 @@
+use std::cmp::Reverse;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const MAX_ENTRIES: usize = 10;
+const QUALIFIER_APP_NAME: &str = "bevy-brick-breaker";
+
+/// One row of the persisted high-score table.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct HighScoreEntry {
+    pub score: u32,
+    pub recorded_at_unix_secs: u64,
+}
+
+/// Top-`MAX_ENTRIES` scores ever recorded, persisted to a config file and
+/// loaded once at startup.
+#[derive(Resource, Serialize, Deserialize, Default)]
+pub struct HighScores {
+    entries: Vec<HighScoreEntry>,
+}
+
+impl HighScores {
+    /// Loads the table from disk, falling back to an empty one if the file
+    /// is missing or can't be parsed. There is no config directory on
+    /// wasm32, so the table simply starts empty there.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        let Some(path) = config_path() else {
+            return Self::default();
+        };
+
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        Self::default()
+    }
+
+    /// Persists the table to disk; a no-op on wasm32, which has nowhere to
+    /// write it.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        let Some(path) = config_path() else {
+            return;
+        };
+        let Ok(contents) = serde_json::to_string_pretty(self) else {
+            return;
+        };
+
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(path, contents);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {}
+
+    pub fn entries(&self) -> &[HighScoreEntry] {
+        &self.entries
+    }
+
+    /// Inserts `score` if it ranks in the top `MAX_ENTRIES`, keeping the
+    /// table sorted highest-first. Returns whether it was inserted.
+    pub fn insert_if_qualifies(&mut self, score: u32, recorded_at_unix_secs: u64) -> bool {
+        if self.entries.len() >= MAX_ENTRIES
+            && self.entries.last().is_some_and(|lowest| lowest.score >= score)
+        {
+            return false;
+        }
+
+        self.entries.push(HighScoreEntry {
+            score,
+            recorded_at_unix_secs,
+        });
+        self.entries.sort_by_key(|entry| Reverse(entry.score));
+        self.entries.truncate(MAX_ENTRIES);
+        true
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn config_path() -> Option<PathBuf> {
+    directories::ProjectDirs::from("dev", "jamessizeland", QUALIFIER_APP_NAME)
+        .map(|dirs| dirs.config_dir().join("high_scores.json"))
+}
+
+pub fn load_high_scores(mut commands: Commands) {
+    commands.insert_resource(HighScores::load());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_into_an_empty_table() {
+        let mut high_scores = HighScores::default();
+
+        assert!(high_scores.insert_if_qualifies(10, 0));
+        assert_eq!(high_scores.entries().len(), 1);
+        assert_eq!(high_scores.entries()[0].score, 10);
+    }
+
+    #[test]
+    fn keeps_entries_sorted_highest_first() {
+        let mut high_scores = HighScores::default();
+
+        high_scores.insert_if_qualifies(10, 0);
+        high_scores.insert_if_qualifies(30, 0);
+        high_scores.insert_if_qualifies(20, 0);
+
+        let scores: Vec<u32> = high_scores.entries().iter().map(|entry| entry.score).collect();
+        assert_eq!(scores, vec![30, 20, 10]);
+    }
+
+    #[test]
+    fn truncates_to_max_entries_once_full() {
+        let mut high_scores = HighScores::default();
+
+        for score in 0..(MAX_ENTRIES as u32 + 1) {
+            high_scores.insert_if_qualifies(score, 0);
+        }
+
+        assert_eq!(high_scores.entries().len(), MAX_ENTRIES);
+        // The lowest score (0) was pushed out by the table filling up.
+        assert!(high_scores.entries().iter().all(|entry| entry.score != 0));
+    }
+
+    #[test]
+    fn rejects_a_score_tied_with_the_lowest_entry_once_full() {
+        let mut high_scores = HighScores::default();
+
+        for score in 1..=(MAX_ENTRIES as u32) {
+            high_scores.insert_if_qualifies(score, 0);
+        }
+
+        assert!(!high_scores.insert_if_qualifies(1, 0));
+        assert_eq!(high_scores.entries().len(), MAX_ENTRIES);
+    }
+
+    #[test]
+    fn accepts_a_score_that_beats_the_lowest_entry_once_full() {
+        let mut high_scores = HighScores::default();
+
+        for score in 1..=(MAX_ENTRIES as u32) {
+            high_scores.insert_if_qualifies(score, 0);
+        }
+
+        assert!(high_scores.insert_if_qualifies(2, 100));
+        assert_eq!(high_scores.entries().len(), MAX_ENTRIES);
+        assert!(high_scores.entries().iter().all(|entry| entry.score != 1));
+    }
+}